@@ -0,0 +1,80 @@
+use tauri::{App, Manager, WindowEvent};
+
+use crate::events::{self, AppState};
+use crate::kiosk::{self, KioskConfig};
+use crate::process::{self, ProcessState};
+use crate::updater::{self, UpdaterConfig, UpdaterState};
+use crate::{exit_app, greet};
+
+type SetupHook = Box<dyn FnOnce(&mut App) -> tauri::Result<()> + Send>;
+
+/// Builds the Tauri application, letting embedders (desktop `main.rs`, the mobile entry point,
+/// integration tests) inject their own [`setup`](AppBuilder::setup) behavior instead of being
+/// locked into a hard-coded setup closure.
+#[derive(Default)]
+pub struct AppBuilder {
+    setup_hook: Option<SetupHook>,
+}
+
+impl AppBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a hook that runs after the built-in kiosk/window setup during `tauri::Builder::setup`.
+    pub fn setup<F>(mut self, hook: F) -> Self
+    where
+        F: FnOnce(&mut App) -> tauri::Result<()> + Send + 'static,
+    {
+        self.setup_hook = Some(Box::new(hook));
+        self
+    }
+
+    pub fn run(self) {
+        std::env::set_var("WEBKIT_DISABLE_DMABUF_RENDERER", "1");
+
+        let setup_hook = self.setup_hook;
+
+        tauri::Builder::default()
+            .plugin(tauri_plugin_opener::init())
+            .plugin(tauri_plugin_updater::Builder::new().build())
+            .manage(ProcessState::default())
+            .manage(UpdaterState::default())
+            .manage(AppState::default())
+            .invoke_handler(tauri::generate_handler![
+                greet,
+                exit_app,
+                process::spawn_process,
+                process::write_stdin,
+                updater::check_for_update,
+                updater::install_update,
+                events::subscribe
+            ])
+            .setup(move |app| {
+                let kiosk_config = KioskConfig::from_app(app);
+                kiosk::setup(app, &kiosk_config)?;
+
+                let updater_config = UpdaterConfig::from_app(app);
+                if updater_config.check_on_startup {
+                    updater::check_on_startup(&app.handle());
+                }
+
+                if let Some(hook) = setup_hook {
+                    hook(app)?;
+                }
+
+                Ok(())
+            })
+            .on_window_event(|window, event| match event {
+                WindowEvent::CloseRequested { .. } => {
+                    process::kill_all(window.state::<ProcessState>().inner());
+                }
+                WindowEvent::Destroyed => {
+                    events::unsubscribe_window(window.state::<AppState>().inner(), window.label());
+                }
+                _ => {}
+            })
+            .run(tauri::generate_context!())
+            .expect("error while running tauri application");
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State, Window};
+use tokio::sync::{broadcast, oneshot};
+
+/// Envelope pushed to subscribers of a topic, mirrored 1:1 to the frontend event payload.
+#[derive(Clone, Serialize)]
+pub struct Message {
+    pub topic: String,
+    pub payload: serde_json::Value,
+    pub ts: u64,
+}
+
+/// Broadcast channels keyed by topic, plus the per-window cancellation handles that let
+/// [`unsubscribe_window`] stop a window's forwarding tasks when it's destroyed.
+#[derive(Default)]
+pub struct AppState {
+    channels: Mutex<HashMap<String, broadcast::Sender<Message>>>,
+    subscriptions: Mutex<HashMap<String, Vec<oneshot::Sender<()>>>>,
+}
+
+/// Drops topic entries whose sender has no remaining receivers, so topics that nobody is (or is
+/// no longer) subscribed to don't accumulate for the lifetime of the app.
+fn prune_empty_channels(channels: &mut HashMap<String, broadcast::Sender<Message>>) {
+    channels.retain(|_, sender| sender.receiver_count() > 0);
+}
+
+/// Pushes `payload` to every current subscriber of `topic`. Other subsystems (sensors, timers,
+/// the process streamer) call this to push asynchronous updates into the UI without polling.
+pub fn broadcast(app: &AppHandle, topic: &str, payload: serde_json::Value) {
+    let state: State<AppState> = app.state();
+    let mut channels = state.channels.lock().unwrap();
+    let sender = channels
+        .entry(topic.to_string())
+        .or_insert_with(|| broadcast::channel(64).0);
+
+    let message = Message {
+        topic: topic.to_string(),
+        payload,
+        ts: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default(),
+    };
+    let _ = sender.send(message);
+
+    prune_empty_channels(&mut channels);
+}
+
+/// Subscribes `window` to `topic`, forwarding every future [`broadcast`] on that topic to a
+/// `topic://<topic>` event on that window until it's destroyed or the channel is dropped.
+///
+/// Registers the receiver and forwarding task before returning, so callers that set up a
+/// subscription synchronously (e.g. [`crate::process::spawn_process`], before it starts
+/// streaming) are guaranteed not to miss any message broadcast after this call returns for
+/// having joined late. A receiver that falls more than 64 messages behind skips the oldest ones.
+pub fn subscribe_window(app: &AppHandle, window: &Window, topic: String) {
+    let mut receiver = {
+        let state: State<AppState> = app.state();
+        let mut channels = state.channels.lock().unwrap();
+        channels
+            .entry(topic.clone())
+            .or_insert_with(|| broadcast::channel(64).0)
+            .subscribe()
+    };
+
+    let (cancel_tx, mut cancel_rx) = oneshot::channel();
+    {
+        let state: State<AppState> = app.state();
+        state
+            .subscriptions
+            .lock()
+            .unwrap()
+            .entry(window.label().to_string())
+            .or_default()
+            .push(cancel_tx);
+    }
+
+    let window_label = window.label().to_string();
+    let event_name = format!("topic://{topic}");
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = &mut cancel_rx => break,
+                message = receiver.recv() => match message {
+                    Ok(message) => {
+                        if app.emit_to(&window_label, &event_name, &message).is_err() {
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+            }
+        }
+
+        drop(receiver);
+        let state: State<AppState> = app.state();
+        prune_empty_channels(&mut state.channels.lock().unwrap());
+    });
+}
+
+/// Subscribes the calling window to `topic`. Thin command wrapper around [`subscribe_window`] for
+/// subsystems that don't need to pre-register a subscription before their first broadcast.
+#[tauri::command]
+pub fn subscribe(app: AppHandle, window: Window, topic: String) -> Result<(), String> {
+    subscribe_window(&app, &window, topic);
+    Ok(())
+}
+
+/// Cancels every subscription forwarding task owned by `window_label`, called when that window is
+/// destroyed so its channel senders don't leak. Each cancelled task prunes its own now-empty
+/// topic once it wakes up and drops its receiver (see [`subscribe_window`]).
+pub fn unsubscribe_window(state: &AppState, window_label: &str) {
+    if let Some(senders) = state.subscriptions.lock().unwrap().remove(window_label) {
+        for sender in senders {
+            let _ = sender.send(());
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use tauri::plugin::TauriPlugin;
+use tauri::{App, Manager, Wry};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+
+const DEFAULT_TOGGLE_SHORTCUT: &str = "CmdOrCtrl+Shift+K";
+
+/// Settings for kiosk mode, read from the `plugins.kiosk` section of `tauri.conf.json`.
+pub struct KioskConfig {
+    pub default_fullscreen: bool,
+    pub toggle_shortcut: String,
+    pub exit_on_shortcut: bool,
+}
+
+impl KioskConfig {
+    pub fn from_app(app: &App) -> Self {
+        let raw = app.config().plugins.0.get("kiosk");
+
+        Self {
+            default_fullscreen: raw
+                .and_then(|v| v.get("default_fullscreen"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true),
+            toggle_shortcut: raw
+                .and_then(|v| v.get("toggle_shortcut"))
+                .and_then(|v| v.as_str())
+                .unwrap_or(DEFAULT_TOGGLE_SHORTCUT)
+                .to_string(),
+            exit_on_shortcut: raw
+                .and_then(|v| v.get("exit_on_shortcut"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// Builds the global-shortcut plugin for `shortcut`, failing if that shortcut string can't be
+/// parsed by `tauri-plugin-global-shortcut`.
+fn build_shortcut_plugin(shortcut: &str, exit_on_shortcut: bool) -> tauri::Result<TauriPlugin<Wry>> {
+    Ok(tauri_plugin_global_shortcut::Builder::new()
+        .with_shortcut(shortcut)?
+        .with_handler(move |app, _shortcut, event| {
+            if event.state() != ShortcutState::Pressed {
+                return;
+            }
+            if let Some(window) = app.get_webview_window("main") {
+                let is_fullscreen = window.is_fullscreen().unwrap_or(false);
+                window.set_fullscreen(!is_fullscreen).ok();
+                window.set_focus().ok();
+            }
+            if exit_on_shortcut {
+                app.exit(0);
+            }
+        })
+        .build())
+}
+
+/// Builds and attaches the global-shortcut plugin for `shortcut` in one step. Fails either if
+/// `shortcut` can't be parsed, or if `tauri-plugin-global-shortcut` can't register it with the
+/// OS (e.g. already bound by another app, or unsupported on the current windowing backend) —
+/// both are the same kind of failure from a caller's perspective and should fall back the same way.
+fn register_shortcut(app: &App, shortcut: &str, exit_on_shortcut: bool) -> tauri::Result<()> {
+    let plugin = build_shortcut_plugin(shortcut, exit_on_shortcut)?;
+    app.handle().plugin(plugin)
+}
+
+/// Applies the configured fullscreen default and registers the toggle shortcut that lets an
+/// operator escape kiosk mode (and optionally quit) without a window chrome to click on.
+///
+/// A `toggle_shortcut` that fails to register — whether because it's unparsable or because the
+/// OS rejects it — must never take the whole kiosk display down at startup, so registration
+/// falls back to [`DEFAULT_TOGGLE_SHORTCUT`] and, failing that, just logs and leaves the escape
+/// hatch disabled rather than propagating out of `app.setup()`.
+pub fn setup(app: &App, config: &KioskConfig) -> tauri::Result<()> {
+    if let Some(window) = app.get_webview_window("main") {
+        window.set_fullscreen(config.default_fullscreen).ok();
+        window.set_focus().ok();
+    }
+
+    if let Err(err) = register_shortcut(app, &config.toggle_shortcut, config.exit_on_shortcut) {
+        eprintln!(
+            "kiosk: failed to register toggle shortcut {:?} ({err}), falling back to default {DEFAULT_TOGGLE_SHORTCUT:?}"
+        );
+        if let Err(err) = register_shortcut(app, DEFAULT_TOGGLE_SHORTCUT, config.exit_on_shortcut) {
+            eprintln!(
+                "kiosk: failed to register default toggle shortcut ({err}), kiosk escape hatch disabled"
+            );
+        }
+    }
+
+    Ok(())
+}
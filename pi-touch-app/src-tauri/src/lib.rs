@@ -1,4 +1,10 @@
-use tauri::Manager;
+mod app_builder;
+mod events;
+mod kiosk;
+mod process;
+mod updater;
+
+pub use app_builder::AppBuilder;
 
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 #[tauri::command]
@@ -13,18 +19,5 @@ fn exit_app(app_handle: tauri::AppHandle) {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    std::env::set_var("WEBKIT_DISABLE_DMABUF_RENDERER", "1");
-
-    tauri::Builder::default()
-        .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![greet, exit_app])
-        .setup(|app| {
-            if let Some(window) = app.get_webview_window("main") {
-                window.set_fullscreen(true).ok();
-                window.set_focus().ok();
-            }
-            Ok(())
-        })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+    AppBuilder::new().run();
 }
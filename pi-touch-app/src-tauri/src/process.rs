@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State, Window};
+
+use crate::events;
+
+/// Tracks running child processes spawned via [`spawn_process`], keyed by session id.
+#[derive(Default)]
+pub struct ProcessState(pub Mutex<HashMap<String, Child>>);
+
+/// Session id plus the topic names its output streams on. The calling window is already
+/// subscribed to these by the time this is returned (see [`spawn_process`]); the names are
+/// returned so the frontend can label/display them or have other windows subscribe too.
+#[derive(Serialize)]
+pub struct SpawnedProcess {
+    pub id: String,
+    pub stdout_topic: String,
+    pub stderr_topic: String,
+    pub exit_topic: String,
+}
+
+#[tauri::command]
+pub fn spawn_process(
+    app: AppHandle,
+    window: Window,
+    program: String,
+    args: Vec<String>,
+) -> Result<SpawnedProcess, String> {
+    let id = format!("{:x}", rand_session_id());
+    let stdout_topic = format!("process/{id}/stdout");
+    let stderr_topic = format!("process/{id}/stderr");
+    let exit_topic = format!("process/{id}/exit");
+
+    // Subscribe the calling window before the child even spawns, so no stdout/stderr/exit
+    // broadcast can fire while nobody is listening yet (a `broadcast` channel doesn't replay
+    // missed messages to subscribers that join late).
+    events::subscribe_window(&app, &window, stdout_topic.clone());
+    events::subscribe_window(&app, &window, stderr_topic.clone());
+    events::subscribe_window(&app, &window, exit_topic.clone());
+
+    let mut child = Command::new(&program)
+        .args(&args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    let stdout = child.stdout.take().ok_or("failed to capture child stdout")?;
+    let stderr = child.stderr.take().ok_or("failed to capture child stderr")?;
+
+    let state: State<ProcessState> = app.state();
+    state.0.lock().unwrap().insert(id.clone(), child);
+
+    let app_for_stderr = app.clone();
+    let stderr_topic_for_thread = stderr_topic.clone();
+    std::thread::spawn(move || {
+        let reader = BufReader::new(stderr);
+        for line in reader.lines() {
+            match line {
+                Ok(line) => {
+                    events::broadcast(
+                        &app_for_stderr,
+                        &stderr_topic_for_thread,
+                        serde_json::Value::String(line),
+                    );
+                }
+                Err(_) => break,
+            }
+        }
+    });
+
+    let app_for_thread = app.clone();
+    let id_for_thread = id.clone();
+    let stdout_topic_for_thread = stdout_topic.clone();
+    let exit_topic_for_thread = exit_topic.clone();
+    std::thread::spawn(move || {
+        let reader = BufReader::new(stdout);
+        for line in reader.lines() {
+            match line {
+                Ok(line) => {
+                    events::broadcast(
+                        &app_for_thread,
+                        &stdout_topic_for_thread,
+                        serde_json::Value::String(line),
+                    );
+                }
+                Err(_) => break,
+            }
+        }
+
+        // Remove the child from the shared map before waiting on it: wait() blocks until exit,
+        // and holding the app-wide lock across that block would stall every other concurrently
+        // running process's spawn_process/write_stdin/kill_all until this one is reaped.
+        let mut removed_child = {
+            let state: State<ProcessState> = app_for_thread.state();
+            state.0.lock().unwrap().remove(&id_for_thread)
+        };
+        let exit_code = removed_child
+            .as_mut()
+            .and_then(|child| child.wait().ok())
+            .and_then(|status| status.code());
+        events::broadcast(&app_for_thread, &exit_topic_for_thread, serde_json::json!(exit_code));
+    });
+
+    Ok(SpawnedProcess {
+        id,
+        stdout_topic,
+        stderr_topic,
+        exit_topic,
+    })
+}
+
+#[tauri::command]
+pub fn write_stdin(app: AppHandle, id: String, line: String) -> Result<(), String> {
+    let state: State<ProcessState> = app.state();
+    let mut children = state.0.lock().unwrap();
+    let child = children.get_mut(&id).ok_or("no such process")?;
+    let stdin = child.stdin.as_mut().ok_or("child stdin not piped")?;
+    writeln!(stdin, "{line}").map_err(|e| e.to_string())
+}
+
+/// Kills every still-running child, used when a window closes so sessions don't outlive it.
+/// Reaps each one on a background thread afterwards — dropping a killed `Child` without
+/// `wait()`ing on it leaves a zombie behind on Linux until the whole app process exits.
+pub fn kill_all(state: &ProcessState) {
+    let mut children = state.0.lock().unwrap();
+    for (_, mut child) in children.drain() {
+        let _ = child.kill();
+        std::thread::spawn(move || {
+            let _ = child.wait();
+        });
+    }
+}
+
+fn rand_session_id() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default();
+    nanos ^ COUNTER.fetch_add(1, Ordering::Relaxed)
+}
@@ -0,0 +1,85 @@
+use std::sync::Mutex;
+
+use tauri::{App, AppHandle, Emitter, Manager, State};
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+/// Holds the update located by the most recent [`check_for_update`] call, ready for
+/// [`install_update`] to download and apply.
+#[derive(Default)]
+pub struct UpdaterState(pub Mutex<Option<Update>>);
+
+/// Settings for the updater, read from the `plugins.updater` section of `tauri.conf.json`.
+pub struct UpdaterConfig {
+    pub check_on_startup: bool,
+}
+
+impl UpdaterConfig {
+    pub fn from_app(app: &App) -> Self {
+        let raw = app.config().plugins.0.get("updater");
+
+        Self {
+            check_on_startup: raw
+                .and_then(|v| v.get("check_on_startup"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// Checks the configured endpoint for a new release and keeps it for [`install_update`]. This
+/// only fetches release metadata; the bundle's signature is checked when it is downloaded.
+#[tauri::command]
+pub async fn check_for_update(app: AppHandle) -> Result<bool, String> {
+    let update = app
+        .updater()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let available = update.is_some();
+    let state: State<UpdaterState> = app.state();
+    *state.0.lock().unwrap() = update;
+    Ok(available)
+}
+
+/// Downloads and installs the update found by [`check_for_update`], emitting `update://progress`
+/// events as bytes arrive and `update://ready` once it's safe to prompt the user to restart.
+/// The updater plugin verifies the downloaded bundle's signature against the configured public
+/// key before installing it, and returns an error for a bundle that fails verification.
+#[tauri::command]
+pub async fn install_update(app: AppHandle) -> Result<(), String> {
+    let update = {
+        let state: State<UpdaterState> = app.state();
+        state.0.lock().unwrap().take()
+    }
+    .ok_or("no update has been checked for")?;
+
+    let mut downloaded = 0u64;
+    let progress_app = app.clone();
+    update
+        .download_and_install(
+            move |chunk_len, total| {
+                downloaded += chunk_len as u64;
+                let _ = progress_app.emit(
+                    "update://progress",
+                    serde_json::json!({ "downloaded": downloaded, "total": total }),
+                );
+            },
+            || {},
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let _ = app.emit("update://ready", ());
+    Ok(())
+}
+
+/// Fires a background [`check_for_update`] at startup; failures are silent since this is a
+/// background convenience check, not a user-initiated action.
+pub fn check_on_startup(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let _ = check_for_update(app).await;
+    });
+}